@@ -3,7 +3,7 @@ use rstd::collections::btree_map::BTreeMap;
 use rstd::collections::btree_map::Values;
 use rstd::iter::Flatten;
 #[cfg(feature = "std")]
-use serde::{Deserialize, Deserializer};
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
 #[cfg(feature = "std")]
 use serde::de::{Visitor, SeqAccess};
 
@@ -13,7 +13,7 @@ use serde_json;
 use std::io;
 
 use operation::Operation;
-use {errors, ErrorKind, Event, Constructor, Function};
+use {errors, ErrorKind, Event, Constructor, Function, Token, Hash, Log, RawLog, AbiError, ParamType, Uint, decode};
 
 use rstd::prelude::*;
 use rstd::vec::Vec;
@@ -21,6 +21,22 @@ use rstd::vec::Vec;
 #[cfg(not(feature = "std"))]
 use alloc::string::String;
 
+/// Selector of the builtin Solidity `Error(string)` revert encoding.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+/// Selector of the builtin Solidity `Panic(uint256)` revert encoding.
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// The outcome of decoding revert bytes via [`Contract::decode_error`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum DecodedError<'a> {
+	/// A builtin `Error(string)` revert reason.
+	Revert(String),
+	/// A builtin `Panic(uint256)` revert code.
+	Panic(Uint),
+	/// A user-defined custom error and its decoded arguments.
+	Custom(&'a AbiError, Vec<Token>),
+}
+
 /// API building calls to contracts ABI.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Contract {
@@ -30,8 +46,25 @@ pub struct Contract {
 	pub functions: BTreeMap<String, Function>,
 	/// Contract events, maps signature to event.
 	pub events: BTreeMap<String, Vec<Event>>,
+	/// Maps an event's topic0 (the keccak of its canonical signature) to the
+	/// non-anonymous events sharing it, for decoding raw logs.
+	pub events_by_topic: BTreeMap<Hash, Vec<Event>>,
+	/// Maps a function's 4-byte selector to the function, for decoding raw calldata.
+	///
+	/// Keyed on the selector rather than the name so overloaded functions
+	/// (same name, different params) each resolve to their own `Function`.
+	pub functions_by_selector: BTreeMap<[u8; 4], Function>,
+	/// Contract errors, maps name to error.
+	pub errors: BTreeMap<String, AbiError>,
+	/// Maps a custom error's 4-byte selector to the error, for decoding revert data.
+	///
+	/// Keyed on the selector rather than the name so overloaded errors
+	/// (same name, different params) each resolve to their own `AbiError`.
+	pub errors_by_selector: BTreeMap<[u8; 4], AbiError>,
 	/// Contract has fallback function.
 	pub fallback: bool,
+	/// Contract has a payable receive function.
+	pub receive: bool,
 }
 
 #[cfg(feature = "std")]
@@ -57,7 +90,12 @@ impl<'a> Visitor<'a> for ContractVisitor {
 			constructor: None,
 			functions: BTreeMap::default(),
 			events: BTreeMap::default(),
+			events_by_topic: BTreeMap::default(),
+			functions_by_selector: BTreeMap::default(),
+			errors: BTreeMap::default(),
+			errors_by_selector: BTreeMap::default(),
 			fallback: false,
+			receive: false,
 		};
 
 		while let Some(operation) = seq.next_element()? {
@@ -66,14 +104,25 @@ impl<'a> Visitor<'a> for ContractVisitor {
 					result.constructor = Some(constructor);
 				},
 				Operation::Function(func) => {
+					result.functions_by_selector.insert(func.short_signature(), func.clone());
 					result.functions.insert(func.name.clone(), func);
 				},
 				Operation::Event(event) => {
+					if !event.anonymous {
+						result.events_by_topic.entry(event.signature()).or_default().push(event.clone());
+					}
 					result.events.entry(event.name.clone()).or_default().push(event);
 				},
+				Operation::Error(error) => {
+					result.errors_by_selector.insert(error.short_signature(), error.clone());
+					result.errors.insert(error.name.clone(), error);
+				},
 				Operation::Fallback => {
 					result.fallback = true;
 				},
+				Operation::Receive => {
+					result.receive = true;
+				},
 			}
 		}
 
@@ -81,6 +130,46 @@ impl<'a> Visitor<'a> for ContractVisitor {
 	}
 }
 
+/// A borrowing view of a single ABI operation, used to re-serialize a `Contract`
+/// back into the flat array-of-operations form Solidity tooling expects.
+#[cfg(feature = "std")]
+#[derive(Serialize)]
+#[serde(tag = "type")]
+enum OperationRef<'a> {
+	#[serde(rename = "constructor")]
+	Constructor(&'a Constructor),
+	#[serde(rename = "function")]
+	Function(&'a Function),
+	#[serde(rename = "event")]
+	Event(&'a Event),
+	#[serde(rename = "error")]
+	Error(&'a AbiError),
+	#[serde(rename = "fallback")]
+	Fallback,
+	#[serde(rename = "receive")]
+	Receive,
+}
+
+#[cfg(feature = "std")]
+impl Serialize for Contract {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+		let mut operations = Vec::new();
+		if let Some(ref constructor) = self.constructor {
+			operations.push(OperationRef::Constructor(constructor));
+		}
+		operations.extend(self.functions_by_selector.values().map(OperationRef::Function));
+		operations.extend(self.events().map(OperationRef::Event));
+		operations.extend(self.errors.values().map(OperationRef::Error));
+		if self.fallback {
+			operations.push(OperationRef::Fallback);
+		}
+		if self.receive {
+			operations.push(OperationRef::Receive);
+		}
+		operations.serialize(serializer)
+	}
+}
+
 impl Contract {
 	#[cfg(feature = "std")]
 	/// Loads contract from json.
@@ -88,6 +177,18 @@ impl Contract {
 		serde_json::from_reader(reader).map_err(From::from)
 	}
 
+	#[cfg(feature = "std")]
+	/// Serializes the contract ABI as JSON to a writer.
+	pub fn save<W: io::Write>(&self, writer: W) -> errors::Result<()> {
+		serde_json::to_writer(writer, self).map_err(From::from)
+	}
+
+	#[cfg(feature = "std")]
+	/// Serializes the contract ABI as a JSON string.
+	pub fn to_string(&self) -> errors::Result<String> {
+		serde_json::to_string(self).map_err(From::from)
+	}
+
 	/// Creates constructor call builder.
 	pub fn constructor(&self) -> Option<&Constructor> {
 		self.constructor.as_ref()
@@ -98,6 +199,53 @@ impl Contract {
 		self.functions.get(name).ok_or_else(|| "Invalid name")
 	}
 
+	/// Get the contract function whose 4-byte selector matches `selector`.
+	pub fn function_by_selector(&self, selector: [u8; 4]) -> errors::Result<&Function> {
+		self.functions_by_selector.get(&selector)
+			.ok_or_else(|| ErrorKind::InvalidName(format!("{:?}", selector)).into())
+	}
+
+	/// Decodes raw calldata (a 4-byte selector followed by ABI-encoded arguments)
+	/// into the matching function and its decoded input tokens.
+	pub fn decode_input(&self, data: &[u8]) -> errors::Result<(&Function, Vec<Token>)> {
+		if data.len() < 4 {
+			return Err(ErrorKind::InvalidData.into());
+		}
+		let mut selector = [0u8; 4];
+		selector.copy_from_slice(&data[..4]);
+		let function = self.function_by_selector(selector)?;
+		let tokens = function.decode_input(&data[4..])?;
+		Ok((function, tokens))
+	}
+
+	/// Decodes a raw log (topics and data) against every event in the contract.
+	///
+	/// Candidates are selected by `topics[0]` (topic0) via the precomputed
+	/// `events_by_topic` index; if the log carries no topics at all, every
+	/// anonymous event is tried instead.
+	pub fn decode_log(&self, topics: &[Hash], data: &[u8]) -> errors::Result<(&Event, Log)> {
+		let raw = RawLog::from((topics.to_vec(), data.to_vec()));
+
+		if topics.is_empty() {
+			for event in self.events().filter(|event| event.anonymous) {
+				if let Ok(log) = event.parse_log(raw.clone()) {
+					return Ok((event, log));
+				}
+			}
+			return Err(ErrorKind::InvalidData.into());
+		}
+
+		let candidates = self.events_by_topic.get(&topics[0])
+			.ok_or_else(|| ErrorKind::InvalidData)?;
+		for event in candidates {
+			if let Ok(log) = event.parse_log(raw.clone()) {
+				return Ok((event, log));
+			}
+		}
+
+		Err(ErrorKind::InvalidData.into())
+	}
+
 	/// Get the contract event named `name`, the first if there are multiple.
 	pub fn event(&self, name: &str) -> Result<&Event, &'static str> {
 		self.events.get(name).into_iter()
@@ -112,6 +260,55 @@ impl Contract {
 					.ok_or_else(|| "Invalid name")
 	}
 
+	/// Get the contract error named `name`.
+	pub fn error(&self, name: &str) -> Result<&AbiError, &'static str> {
+		self.errors.get(name).ok_or_else(|| "Invalid name")
+	}
+
+	/// Get the contract error whose 4-byte selector matches `selector`.
+	pub fn error_by_selector(&self, selector: [u8; 4]) -> errors::Result<&AbiError> {
+		self.errors_by_selector.get(&selector)
+			.ok_or_else(|| ErrorKind::InvalidName(format!("{:?}", selector)).into())
+	}
+
+	/// Iterate over all errors of the contract in arbitrary order.
+	pub fn errors(&self) -> Errors {
+		Errors(self.errors.values())
+	}
+
+	/// Decodes revert bytes, recognizing the builtin `Error(string)` and
+	/// `Panic(uint256)` encodings as well as any user-defined custom error.
+	pub fn decode_error(&self, data: &[u8]) -> errors::Result<DecodedError> {
+		if data.len() < 4 {
+			return Err(ErrorKind::InvalidData.into());
+		}
+		let mut selector = [0u8; 4];
+		selector.copy_from_slice(&data[..4]);
+		let payload = &data[4..];
+
+		match selector {
+			ERROR_SELECTOR => {
+				let reason = match decode(&[ParamType::String], payload)?.into_iter().next() {
+					Some(Token::String(reason)) => reason,
+					_ => return Err(ErrorKind::InvalidData.into()),
+				};
+				Ok(DecodedError::Revert(reason))
+			},
+			PANIC_SELECTOR => {
+				let code = match decode(&[ParamType::Uint(256)], payload)?.into_iter().next() {
+					Some(Token::Uint(code)) => code,
+					_ => return Err(ErrorKind::InvalidData.into()),
+				};
+				Ok(DecodedError::Panic(code))
+			},
+			_ => {
+				let error = self.error_by_selector(selector)?;
+				let tokens = error.decode_input(payload)?;
+				Ok(DecodedError::Custom(error, tokens))
+			},
+		}
+	}
+
 	/// Iterate over all functions of the contract in arbitrary order.
 	pub fn functions(&self) -> Functions {
 		Functions(self.functions.values())
@@ -126,6 +323,11 @@ impl Contract {
 	pub fn fallback(&self) -> bool {
 		self.fallback
 	}
+
+	/// Returns true if contract has a payable receive function.
+	pub fn receive(&self) -> bool {
+		self.receive
+	}
 }
 
 /// Contract functions interator.
@@ -149,3 +351,14 @@ impl<'a> Iterator for Events<'a> {
 		self.0.next()
 	}
 }
+
+/// Contract errors interator.
+pub struct Errors<'a>(Values<'a, String, AbiError>);
+
+impl<'a> Iterator for Errors<'a> {
+	type Item = &'a AbiError;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next()
+	}
+}
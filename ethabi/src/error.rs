@@ -0,0 +1,37 @@
+//! Contract error (Solidity custom error) definition.
+
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+
+use rstd::prelude::*;
+
+use {decode, errors, signature, Param, ParamType, Token};
+
+/// Contract error definition, i.e. a Solidity `error Foo(...)` declaration.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AbiError {
+	/// Error name.
+	pub name: String,
+	/// Error input.
+	pub inputs: Vec<Param>,
+}
+
+impl AbiError {
+	/// Returns all input params of the error.
+	fn param_types(&self) -> Vec<ParamType> {
+		self.inputs.iter().map(|p| p.kind.clone()).collect()
+	}
+
+	/// Returns the 4-byte selector identifying this error in revert data,
+	/// computed the same way as `Function::short_signature`.
+	pub fn short_signature(&self) -> [u8; 4] {
+		signature::short_signature(&self.name, &self.param_types())
+	}
+
+	/// Parses the ABI-encoded error payload (the revert bytes with the
+	/// leading 4-byte selector already stripped) into tokens.
+	pub fn decode_input(&self, data: &[u8]) -> errors::Result<Vec<Token>> {
+		decode(&self.param_types(), data).map_err(From::from)
+	}
+}
@@ -0,0 +1,47 @@
+//! Ethereum contract interface (ABI) definitions.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+extern crate core as rstd;
+#[cfg(feature = "std")]
+extern crate std as rstd;
+
+mod constructor;
+mod contract;
+mod decoder;
+mod encoder;
+mod error;
+mod errors;
+mod event;
+mod event_param;
+mod function;
+mod log;
+mod operation;
+mod param;
+mod param_type;
+mod signature;
+mod token;
+
+pub use constructor::Constructor;
+pub use contract::{Contract, Events, Functions, Errors, DecodedError};
+pub use decoder::decode;
+pub use encoder::encode;
+pub use error::AbiError;
+pub use errors::{Error, ErrorKind};
+pub use event::Event;
+pub use event_param::EventParam;
+pub use function::Function;
+pub use log::{Log, LogParam, RawLog};
+pub use operation::Operation;
+pub use param::Param;
+pub use param_type::ParamType;
+pub use token::Token;
+
+#[cfg(feature = "std")]
+pub use ethereum_types::{H256 as Hash, U256 as Uint};
+#[cfg(not(feature = "std"))]
+pub use uint::{H256 as Hash, U256 as Uint};
@@ -0,0 +1,31 @@
+//! Contract operation, as listed in the flat ABI JSON array.
+
+#[cfg(feature = "std")]
+use serde::Deserialize;
+
+use {AbiError, Constructor, Event, Function};
+
+/// A single entry of a contract's ABI.
+#[cfg_attr(feature = "std", derive(Deserialize))]
+#[cfg_attr(feature = "std", serde(tag = "type"))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Operation {
+	/// Contract constructor.
+	#[cfg_attr(feature = "std", serde(rename = "constructor"))]
+	Constructor(Constructor),
+	/// Contract function.
+	#[cfg_attr(feature = "std", serde(rename = "function"))]
+	Function(Function),
+	/// Contract event.
+	#[cfg_attr(feature = "std", serde(rename = "event"))]
+	Event(Event),
+	/// Contract custom error.
+	#[cfg_attr(feature = "std", serde(rename = "error"))]
+	Error(AbiError),
+	/// Contract fallback function.
+	#[cfg_attr(feature = "std", serde(rename = "fallback"))]
+	Fallback,
+	/// Contract payable receive function.
+	#[cfg_attr(feature = "std", serde(rename = "receive"))]
+	Receive,
+}